@@ -57,13 +57,21 @@ game_setup! {
             selected: GridPos::new(0, 0),
             cursor: Point::new(0.0, 0.0, 0.0),
             resource_count: 10,
+            difficulty: Difficulty::Medium,
+            elapsed: 0.0,
+            wave: 0,
+            wave_enemies_remaining: 0,
         }),
         UnitManager => UnitManager::new(),
-        EnemyManager => EnemyManager::new()
+        EnemyManager => EnemyManager::new(),
+        BulletManager => BulletManager::new(),
+        DebrisManager => DebrisManager::new()
 
     systems:
         manager_update,
-        enemy_update
+        enemy_update,
+        bullet_update,
+        debris_update
 
     models:
         "meshes/cube.dae",
@@ -107,7 +115,7 @@ fn scene_setup(scene: &Scene) {
         // Add to the grid for future looooookups.
         game_manager.grid.insert(GridPos::new(0, 0), base_entity);
 
-        unit_manager.assign(base_entity, PlayerUnit::Base { level: 1 });
+        unit_manager.assign(base_entity, PlayerUnit::Base { level: 1, health: BASE_HEALTH });
 
         let mut base_transform = transform_manager.get_mut(base_entity);
         base_transform.set_position(GridPos::new(0, 0).cell_center());
@@ -124,19 +132,16 @@ fn scene_setup(scene: &Scene) {
 }
 
 fn scene_reset(scene: &Scene) {
-    let enemy_manager = scene.get_manager::<EnemyManager>();
     let collider_manager = scene.get_manager::<ColliderManager>();
     let alarm_manager = scene.get_manager::<AlarmManager>();
 
-    // Register callbacks to patch things up after hotloading.
+    // Register callbacks to patch things up after hotloading. Wave spawning is driven from
+    // `manager_update`, which kicks off the first wave once the board is clear.
     collider_manager.register_callback(on_enemy_collision);
+    collider_manager.register_callback(on_bullet_collision);
     alarm_manager.register_callback(spawn_enemy);
     alarm_manager.register_callback(fire_turret);
-
-    println!("num enemies: {}", enemy_manager.len());
-    if enemy_manager.len() < MIN_ENEMY_COUNT {
-        alarm_manager.assign(scene.create_entity(), ENEMY_SPAWN_DELAY, spawn_enemy);
-    }
+    alarm_manager.register_callback(destroy_debris);
 }
 
 const CELL_SIZE: f32 = 5.0;
@@ -162,6 +167,54 @@ pub struct GameData {
     cursor: Point,
 
     resource_count: usize,
+
+    /// The difficulty tier, which tunes how turrets acquire and fire on targets.
+    difficulty: Difficulty,
+
+    /// Seconds elapsed since the scene started, accumulated in `manager_update`. Used as the clock
+    /// for turret convergence timing without needing per-alarm deltas.
+    elapsed: f32,
+
+    /// The current wave number. Wave 0 is the pre-game state; the first real wave is 1.
+    wave: usize,
+
+    /// How many enemies from the current wave have yet to be spawned. The wave is fully released
+    /// once this reaches zero, and the next wave starts once the board is also clear.
+    wave_enemies_remaining: usize,
+}
+
+/// The game's difficulty tier. Higher difficulties make turrets converge faster, shoot
+/// straighter and fire more often.
+#[derive(Debug, Clone, Copy)]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+/// Per-difficulty turret tuning, looked up from `Difficulty::turret_params`.
+#[derive(Debug, Clone, Copy)]
+struct TurretParams {
+    /// Seconds a turret must hold a newly-acquired target before it is allowed to fire.
+    convergence_time: f32,
+
+    /// Maximum launch-angle perturbation, in radians, sampled symmetrically per shot.
+    spread: f32,
+
+    /// Seconds between shots, used as the repeating fire alarm's interval.
+    fire_interval: f32,
+}
+
+impl Difficulty {
+    fn turret_params(&self) -> TurretParams {
+        match *self {
+            // Slow to lock on and sprays wildly.
+            Difficulty::Easy => TurretParams { convergence_time: 1.5, spread: 0.35, fire_interval: 1.5 },
+            Difficulty::Medium => TurretParams { convergence_time: 0.75, spread: 0.15, fire_interval: 1.0 },
+            // Near-instant lock and almost perfect aim.
+            Difficulty::Hard => TurretParams { convergence_time: 0.25, spread: 0.03, fire_interval: 0.5 },
+        }
+    }
 }
 
 /// Represents a coordinate in the the 2D game grid.
@@ -224,6 +277,26 @@ fn manager_update(scene: &Scene, delta: f32) {
     let camera_manager = scene.get_manager::<CameraManager>();
     let mesh_manager = scene.get_manager::<MeshManager>();
     let alarm_manager = scene.get_manager::<AlarmManager>();
+    let enemy_manager = scene.get_manager::<EnemyManager>();
+    let collider_manager = scene.get_manager::<ColliderManager>();
+
+    // Advance the game clock used for turret convergence timing.
+    game_manager.elapsed += delta;
+
+    // Drive the wave system: once the current wave is fully spawned and cleared off the board,
+    // reward the player and release the next, larger wave after a short cooldown.
+    if game_manager.wave_enemies_remaining == 0 && enemy_manager.len() == 0 {
+        if game_manager.wave > 0 {
+            game_manager.resource_count += WAVE_CLEAR_BONUS;
+        }
+
+        game_manager.wave += 1;
+        game_manager.wave_enemies_remaining = wave_enemy_count(game_manager.wave);
+
+        // The very first wave starts promptly; later waves get a breather in between.
+        let cooldown = if game_manager.wave == 1 { ENEMY_SPAWN_DELAY } else { WAVE_COOLDOWN };
+        alarm_manager.assign(scene.create_entity(), cooldown, spawn_enemy);
+    }
 
     // Handle mouse movement to move the cursor and selected grid cell.
     {
@@ -256,11 +329,18 @@ fn manager_update(scene: &Scene, delta: f32) {
             transform.set_position(game_manager.selected.cell_center());
             transform.set_scale(Vector3::new(0.5, 0.5, 1.0));
             mesh_manager.assign(unit_entity, "pCube1-lib");
-            let alarm_id = alarm_manager.assign_repeating(unit_entity, TURRET_FIRE_INTERVAL, fire_turret);
+            collider_manager.assign(unit_entity, Collider::Box {
+                offset: Vector3::zero(),
+                widths: Vector3::new(0.5, 0.5, 1.0),
+            });
+            let fire_interval = game_manager.difficulty.turret_params().fire_interval;
+            let alarm_id = alarm_manager.assign_repeating(unit_entity, fire_interval, fire_turret);
             unit_manager.assign(unit_entity, PlayerUnit::Turret {
                 level: 1,
                 shoot_alarm: alarm_id,
                 target: None,
+                acquired_at: None,
+                health: TURRET_HEALTH,
             });
 
             game_manager.grid.insert(game_manager.selected, unit_entity);
@@ -270,27 +350,34 @@ fn manager_update(scene: &Scene, delta: f32) {
 
     if game_manager.resource_count > 0 && scene.input.mouse_button_pressed(1) {
         // Find element in grid cell.
-        if let Some(entity) = game_manager.grid.get(&game_manager.selected) {
-            let entity = *entity;
-            match *unit_manager.get_mut(entity).unwrap() {
-                PlayerUnit::Base { ref mut level } => {
-                    // Update level.
-                    *level += 1;
-                    game_manager.resource_count -= 1;
-
-                    // Update the base's scale.
-                    let mut base_transform = transform_manager.get_mut(entity);
-                    base_transform.set_scale(Vector3::new(
-                        *level as f32 * CELL_SIZE * BASE_SCALE_PER_LEVEL,
-                        *level as f32 * CELL_SIZE * BASE_SCALE_PER_LEVEL,
-                        *level as f32 * CELL_SIZE * BASE_SCALE_PER_LEVEL));
-                },
-                PlayerUnit::Turret { ref mut level, shoot_alarm: _, target: _ } => {
-                    *level += 1;
-                    game_manager.resource_count -= 1;
-
-                    // TODO: Adjust the turret based on its new level.
-                },
+        if let Some(entity) = game_manager.grid.get(&game_manager.selected).copied() {
+            // The stored entity can have been destroyed since it was placed (e.g. a turret killed
+            // by enemy contact), leaving a stale grid entry. Prune it instead of unwrapping a dead
+            // component.
+            if let Some(mut unit) = unit_manager.get_mut(entity) {
+                match *unit {
+                    PlayerUnit::Base { ref mut level, .. } => {
+                        // Update level.
+                        *level += 1;
+                        game_manager.resource_count -= 1;
+
+                        // Update the base's scale.
+                        let mut base_transform = transform_manager.get_mut(entity);
+                        base_transform.set_scale(Vector3::new(
+                            *level as f32 * CELL_SIZE * BASE_SCALE_PER_LEVEL,
+                            *level as f32 * CELL_SIZE * BASE_SCALE_PER_LEVEL,
+                            *level as f32 * CELL_SIZE * BASE_SCALE_PER_LEVEL));
+                    },
+                    PlayerUnit::Turret { ref mut level, .. } => {
+                        *level += 1;
+                        game_manager.resource_count -= 1;
+
+                        // TODO: Adjust the turret based on its new level.
+                    },
+                }
+            } else {
+                // Stale entry pointing at a destroyed unit; drop it from the grid.
+                game_manager.grid.remove(&game_manager.selected);
             }
         }
     }
@@ -323,17 +410,31 @@ fn manager_update(scene: &Scene, delta: f32) {
     }
 }
 
-const TURRET_FIRE_INTERVAL: f32 = 1.0;
+const TURRET_RANGE: f32 = 6.0 * CELL_SIZE;
+
+const BASE_HEALTH: f32 = 20.0;
+const TURRET_HEALTH: f32 = 5.0;
+const ENEMY_HEALTH: f32 = 3.0;
+const BULLET_POWER: f32 = 1.0;
+const ENEMY_POWER: f32 = 1.0;
+const ENEMY_KILL_REWARD: usize = 1;
 
 #[derive(Debug, Clone)]
 enum PlayerUnit {
     Base {
         level: usize,
+        health: f32,
     },
     Turret {
         level: usize,
         shoot_alarm: AlarmId,
         target: Option<Entity>,
+
+        /// The game clock time (`GameData::elapsed`) at which the current `target` was acquired, or
+        /// `None` when there is no target. The turret may only fire once it has held a target for
+        /// its difficulty's convergence time.
+        acquired_at: Option<f32>,
+        health: f32,
     },
 }
 
@@ -343,29 +444,311 @@ fn fire_turret(scene: &Scene, turret_entity: Entity) {
     let mut transform_manager = scene.get_manager_mut::<TransformManager>();
     let enemy_manager = scene.get_manager::<EnemyManager>();
     let unit_manager = scene.get_manager::<UnitManager>();
+    let mesh_manager = scene.get_manager::<MeshManager>();
+    let collider_manager = scene.get_manager::<ColliderManager>();
+    let bullet_manager = scene.get_manager::<BulletManager>();
+    let game_manager = scene.get_manager::<GameManager>();
+    let game_manager = &**game_manager;
+
+    let params = game_manager.difficulty.turret_params();
+    let turret_pos = transform_manager.get_mut(turret_entity).position();
+
+    let mut turret = unit_manager.get_mut(turret_entity).unwrap();
+    let (target, acquired_at) = match *turret {
+        PlayerUnit::Turret { ref mut target, ref mut acquired_at, .. } => (target, acquired_at),
+
+        // The base never shoots, and the alarm is only ever assigned to turrets anyway.
+        PlayerUnit::Base { .. } => return,
+    };
+
+    // If we already have a target, make sure it's still alive and in range. A target can be
+    // destroyed out from under us by `on_enemy_collision` between alarm ticks, so we always
+    // re-check `enemy_manager.get` before touching its transform.
+    if let Some(entity) = *target {
+        let still_valid = enemy_manager.get(entity).is_some()
+            && dist_sq(transform_manager.get_mut(entity).position(), turret_pos) <= TURRET_RANGE * TURRET_RANGE;
+        if !still_valid {
+            *target = None;
+            *acquired_at = None;
+        }
+    }
+
+    // No valid target: scan every enemy and lock onto the closest one in range, stamping the
+    // acquisition time so the convergence delay starts counting.
+    if target.is_none() {
+        let mut closest: Option<(Entity, f32)> = None;
+        for (_, enemy_entity) in enemy_manager.iter() {
+            let distance = dist_sq(transform_manager.get_mut(enemy_entity).position(), turret_pos);
+            if distance <= TURRET_RANGE * TURRET_RANGE {
+                match closest {
+                    Some((_, best)) if best <= distance => {},
+                    _ => closest = Some((enemy_entity, distance)),
+                }
+            }
+        }
 
-    let mut turret = unit_manager.get_mut(turret_entity);
+        *target = closest.map(|(entity, _)| entity);
+        if target.is_some() {
+            *acquired_at = Some(game_manager.elapsed);
+        }
+    }
+
+    // Shoot at the held target. Re-check liveness one last time so a target destroyed during the
+    // scan above can't make us dereference a dead entity's transform.
+    if let Some(entity) = *target {
+        // Only fire once the target has been held long enough to converge.
+        let converged = match *acquired_at {
+            Some(acquired) => game_manager.elapsed - acquired >= params.convergence_time,
+            None => false,
+        };
+
+        if converged && enemy_manager.get(entity).is_some() {
+            let target_pos = transform_manager.get_mut(entity).position();
+
+            // Perturb the launch direction by a random angle in the ground plane so shots can miss;
+            // the spread shrinks as difficulty rises.
+            let aim = (target_pos - turret_pos).normalized();
+            let angle = random::range(-params.spread, params.spread);
+            let (sin, cos) = (angle.sin(), angle.cos());
+            let direction = Vector3::new(
+                aim.x * cos - aim.y * sin,
+                aim.x * sin + aim.y * cos,
+                aim.z);
+
+            // Spawn a bullet that flies straight along this heading. Because it never re-aims, the
+            // spread error above sticks and inaccurate shots actually miss.
+            let bullet_entity = scene.create_entity();
+            {
+                let mut bullet_transform = transform_manager.assign(bullet_entity);
+                bullet_transform.set_position(turret_pos);
+                bullet_transform.set_scale(Vector3::new(BULLET_RADIUS, BULLET_RADIUS, BULLET_RADIUS));
+            }
+            mesh_manager.assign(bullet_entity, "pSphere1-lib");
+            collider_manager.assign(bullet_entity, Collider::Sphere {
+                offset: Vector3::zero(),
+                radius: BULLET_RADIUS,
+            });
+            collider_manager.assign_callback(bullet_entity, on_bullet_collision);
+            bullet_manager.assign(bullet_entity, Bullet {
+                speed: BULLET_SPEED,
+                direction: direction,
+                lifetime: 0.0,
+            });
+        }
+    }
+}
 
-    // If the turret already has a target then shoot at that target. Unless that target is dead.
-    // How do we get notified when the target is destroyed.
+/// Squared distance between two world points. Used for range checks where the actual distance
+/// isn't needed, saving a square root.
+fn dist_sq(a: Point, b: Point) -> f32 {
+    let diff = a - b;
+    diff.x * diff.x + diff.y * diff.y + diff.z * diff.z
 }
 
+const BULLET_SPEED: f32 = 20.0;
+const BULLET_RADIUS: f32 = 0.25;
+const BULLET_MAX_LIFETIME: f32 = 3.0;
+
 #[derive(Debug, Clone)]
 struct Bullet {
+    /// How fast the bullet travels along `direction`, in world units per second.
     speed: f32,
+
+    /// The fixed launch heading. Bullets fly straight and never re-aim, so the per-shot spread
+    /// error baked in at launch persists and low-accuracy shots miss.
+    direction: Vector3,
+
+    /// Seconds the bullet has been alive, used to self-destroy past `BULLET_MAX_LIFETIME`.
+    lifetime: f32,
 }
 
 type BulletManager = StructComponentManager<Bullet>;
 
+fn bullet_update(scene: &Scene, delta: f32) {
+    let mut transform_manager = scene.get_manager_mut::<TransformManager>();
+    let bullet_manager = scene.get_manager::<BulletManager>();
+
+    // Collect first so we can destroy bullets mid-loop without invalidating the iterator.
+    let bullet_entities = bullet_manager.iter().map(|(_, entity)| entity).collect::<Vec<_>>();
+    for bullet_entity in bullet_entities {
+        let mut bullet = bullet_manager.get_mut(bullet_entity).unwrap();
+
+        // Bullets fly straight along their launch heading -- no homing -- so the spread error
+        // baked in at fire time is never corrected.
+        transform_manager.get_mut(bullet_entity).translate(bullet.direction * bullet.speed * delta);
+
+        // Age the bullet out so spawned projectiles don't leak entities forever.
+        bullet.lifetime += delta;
+        if bullet.lifetime >= BULLET_MAX_LIFETIME {
+            scene.destroy_entity(bullet_entity);
+        }
+    }
+}
+
+fn on_bullet_collision(scene: &Scene, bullet_entity: Entity, others: &[Entity]) {
+    let enemy_manager = scene.get_manager::<EnemyManager>();
+
+    for other in others.iter().cloned() {
+        // Bullets only hurt enemies; skip the turret that fired us and anything else.
+        if enemy_manager.get(other).is_none() {
+            continue;
+        }
+
+        // Consume the bullet and apply its damage to the enemy it struck.
+        scene.destroy_entity(bullet_entity);
+        damage_enemy(scene, other, BULLET_POWER);
+        return;
+    }
+}
+
 #[derive(Debug, Clone)]
-struct Enemy;
+struct Enemy {
+    health: f32,
+}
 
 type EnemyManager = StructComponentManager<Enemy>;
 
-const MIN_ENEMY_COUNT: usize = 5;
+/// Apply `power` damage to an enemy, destroying it and rewarding the player only once its health
+/// reaches zero. Safe to call on an entity that has already been destroyed -- a missing component
+/// is treated as "already dead".
+fn damage_enemy(scene: &Scene, enemy_entity: Entity, power: f32) {
+    let enemy_manager = scene.get_manager::<EnemyManager>();
+
+    let died = match enemy_manager.get_mut(enemy_entity) {
+        Some(mut enemy) => {
+            enemy.health -= power;
+            enemy.health <= 0.0
+        },
+        None => return,
+    };
+
+    if died {
+        explode(scene, enemy_entity);
+        scene.destroy_entity(enemy_entity);
+
+        // Killing an enemy awards the player a resource ("score").
+        let mut game_manager = scene.get_manager_mut::<GameManager>();
+        game_manager.resource_count += ENEMY_KILL_REWARD;
+    }
+}
+
+/// Apply `power` damage to a player unit, destroying it once its health reaches zero. Destroying
+/// the base is game over.
+fn damage_unit(scene: &Scene, unit_entity: Entity, power: f32) {
+    let unit_manager = scene.get_manager::<UnitManager>();
+
+    let (died, is_base) = match unit_manager.get_mut(unit_entity) {
+        Some(mut unit) => match *unit {
+            PlayerUnit::Base { ref mut health, .. } => {
+                *health -= power;
+                (*health <= 0.0, true)
+            },
+            PlayerUnit::Turret { ref mut health, .. } => {
+                *health -= power;
+                (*health <= 0.0, false)
+            },
+        },
+        None => return,
+    };
+
+    if died {
+        explode(scene, unit_entity);
+        scene.destroy_entity(unit_entity);
+        if is_base {
+            // Losing the base is game over: drop its grid entry so nothing points at the dead
+            // entity anymore.
+            let mut game_manager = scene.get_manager_mut::<GameManager>();
+            game_manager.grid.remove(&GridPos::new(0, 0));
+        }
+    }
+}
+
+const DEBRIS_LIFETIME: f32 = 1.0;
+const DEBRIS_MAX_LARGE: usize = 8;
+const DEBRIS_MAX_SMALL: usize = 16;
+const DEBRIS_SPEED: f32 = 5.0;
+
+/// A flying chunk of a destroyed entity. Purely cosmetic: `debris_update` carries it along
+/// `velocity` until a one-shot alarm cleans it up.
+#[derive(Debug, Clone)]
+struct Debris {
+    velocity: Vector3,
+}
+
+type DebrisManager = StructComponentManager<Debris>;
+
+/// Shatter a dying entity into a burst of debris at its current position. The number of chunks
+/// scales with the entity's size so bigger things make a bigger mess.
+fn explode(scene: &Scene, entity: Entity) {
+    let (position, scale) = {
+        let transform_manager = scene.get_manager_mut::<TransformManager>();
+        let transform = transform_manager.get_mut(entity);
+        (transform.position(), transform.scale().x)
+    };
+    spawn_debris(scene, position, scale);
+}
+
+fn spawn_debris(scene: &Scene, position: Point, scale: f32) {
+    let mut transform_manager = scene.get_manager_mut::<TransformManager>();
+    let mesh_manager = scene.get_manager::<MeshManager>();
+    let debris_manager = scene.get_manager::<DebrisManager>();
+    let alarm_manager = scene.get_manager::<AlarmManager>();
+
+    // One large chunk per unit of scale plus twice as many small ones, each count capped so a
+    // huge base doesn't bury the scene in entities.
+    let large = (scale as usize).min(DEBRIS_MAX_LARGE);
+    let small = ((scale * 2.0) as usize).min(DEBRIS_MAX_SMALL);
+
+    for i in 0..(large + small) {
+        let chunk_scale = if i < large { 0.4 } else { 0.2 };
+
+        let entity = scene.create_entity();
+        {
+            let mut transform = transform_manager.assign(entity);
+            transform.set_position(position);
+            transform.set_scale(Vector3::new(chunk_scale, chunk_scale, chunk_scale));
+        }
+        mesh_manager.assign(entity, "pCube1-lib");
+
+        let velocity = Vector3::new(
+            random::range(-1.0, 1.0),
+            random::range(-1.0, 1.0),
+            random::range(-1.0, 1.0)).normalized() * DEBRIS_SPEED;
+        debris_manager.assign(entity, Debris { velocity: velocity });
+
+        // Reuse the alarm-based cleanup pattern to remove the chunk after a short lifetime.
+        alarm_manager.assign(entity, DEBRIS_LIFETIME, destroy_debris);
+    }
+}
+
+fn destroy_debris(scene: &Scene, entity: Entity) {
+    scene.destroy_entity(entity);
+}
+
+fn debris_update(scene: &Scene, delta: f32) {
+    let mut transform_manager = scene.get_manager_mut::<TransformManager>();
+    let debris_manager = scene.get_manager::<DebrisManager>();
+
+    for (debris, entity) in debris_manager.iter() {
+        transform_manager.get_mut(entity).translate(debris.velocity * delta);
+    }
+}
+
 const ENEMY_SPAWN_DELAY: f32 = 1.0;
 const ENEMY_RADIUS: f32 = 1.0;
 
+const WAVE_BASE_COUNT: usize = 5;
+const WAVE_COUNT_GROWTH: usize = 2;
+const WAVE_COOLDOWN: f32 = 3.0;
+const WAVE_CLEAR_BONUS: usize = 5;
+const ENEMY_SPAWN_RADIUS: f32 = 8.0 * CELL_SIZE;
+
+/// The number of enemies released in a given wave. Grows linearly so each wave is a little harder
+/// than the last.
+fn wave_enemy_count(wave: usize) -> usize {
+    WAVE_BASE_COUNT + wave.saturating_sub(1) * WAVE_COUNT_GROWTH
+}
+
 fn enemy_update(scene: &Scene, delta: f32) {
     const ENEMY_MOVE_SPEED: f32 = 1.0;
 
@@ -390,30 +773,40 @@ fn spawn_enemy(scene: &Scene, entity: Entity) {
     let mesh_manager = scene.get_manager::<MeshManager>();
     let collider_manager = scene.get_manager::<ColliderManager>();
 
+    // Spawn somewhere on a ring around the base so later waves can attack from any direction.
     let mut transform = transform_manager.assign(entity);
+    let angle = random::range(0.0, 2.0 * ::std::f32::consts::PI);
+    let center = GridPos::new(0, 0).cell_center();
     let position = Point::new(
-        random::range(-5.0, 5.0) * CELL_SIZE,
-        random::range(5.0, 10.0) * CELL_SIZE,
+        center.x + angle.cos() * ENEMY_SPAWN_RADIUS,
+        center.y + angle.sin() * ENEMY_SPAWN_RADIUS,
         0.0
     );
     transform.set_position(position);
     transform.set_scale(Vector3::new(ENEMY_RADIUS, ENEMY_RADIUS, ENEMY_RADIUS));
     mesh_manager.assign(entity, "pSphere1-lib");
-    enemy_manager.assign(entity, Enemy);
+    enemy_manager.assign(entity, Enemy { health: ENEMY_HEALTH });
     collider_manager.assign(entity, Collider::Sphere {
         offset: Vector3::zero(),
         radius: ENEMY_RADIUS,
     });
     collider_manager.assign_callback(entity, on_enemy_collision);
 
-    if enemy_manager.len() < MIN_ENEMY_COUNT {
+    // Account for the enemy we just released and keep spawning this wave on a fixed cadence until
+    // its quota is exhausted.
+    let mut game_manager = scene.get_manager_mut::<GameManager>();
+    if game_manager.wave_enemies_remaining > 0 {
+        game_manager.wave_enemies_remaining -= 1;
+    }
+    if game_manager.wave_enemies_remaining > 0 {
         alarm_manager.assign(scene.create_entity(), ENEMY_SPAWN_DELAY, spawn_enemy);
     }
 }
 
 fn on_enemy_collision(scene: &Scene, enemy_entity: Entity, others: &[Entity]) {
-    let alarm_manager = scene.get_manager::<AlarmManager>();
     let enemy_manager = scene.get_manager::<EnemyManager>();
+    let unit_manager = scene.get_manager::<UnitManager>();
+    let bullet_manager = scene.get_manager::<BulletManager>();
 
     for other in others.iter().cloned() {
         // Ignore collisions between two enemies.
@@ -421,19 +814,19 @@ fn on_enemy_collision(scene: &Scene, enemy_entity: Entity, others: &[Entity]) {
             continue;
         }
 
-        // TODO: Check if the other entity is a player unit. If so we damage it.
-
-        // TODO: Check if the other entity is a player's bullet. If so damage the enemy.
-
-        // For now, just destroy the enemy on collision.
-        scene.destroy_entity(enemy_entity);
-
-        // See if we should start spawning new enemies.
-        if enemy_manager.len() < MIN_ENEMY_COUNT {
-            alarm_manager.assign(scene.create_entity(), ENEMY_SPAWN_DELAY, spawn_enemy);
+        // Bullets damage the enemy from their own side in `on_bullet_collision`; nothing to do here.
+        if bullet_manager.get(other).is_some() {
+            continue;
         }
 
-        return;
+        // The remaining case we care about is hitting a player unit: the enemy deals contact damage
+        // to it and is consumed on impact.
+        if unit_manager.get(other).is_some() {
+            damage_unit(scene, other, ENEMY_POWER);
+            explode(scene, enemy_entity);
+            scene.destroy_entity(enemy_entity);
+            return;
+        }
     }
 }
 